@@ -0,0 +1,259 @@
+//! The driver, which owns the audio pipeline and the global event store.
+
+use crate::events::{
+    Event, EventData, EventFilter, EventHandler, EventStore, EventSubscription, Fraction,
+    QuantizedInterval,
+};
+use std::time::Duration;
+
+/// The musical grid against which [`Event::Quantized`] firings are aligned.
+///
+/// A *cycle* is one bar, spanning `cycles_per_bar` beats of `60 / bpm` seconds
+/// each; a [`Fraction`] subdivision is taken relative to that bar, so
+/// `1/cycles_per_bar` lands on each beat and `1/1` on each bar boundary.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TempoContext {
+    /// The tempo in beats per minute.
+    pub beats_per_minute: f64,
+    /// The number of beats in one bar (one cycle).
+    pub cycles_per_bar: u32,
+}
+
+impl TempoContext {
+    /// The duration of a single beat.
+    ///
+    /// # Panics
+    /// Panics if `beats_per_minute` is not finite and strictly positive.
+    /// [`Driver::set_tempo`] enforces this, so the invariant only needs care
+    /// when constructing a `TempoContext` by hand.
+    #[must_use]
+    pub fn beat(&self) -> Duration {
+        assert!(
+            self.beats_per_minute.is_finite() && self.beats_per_minute > 0.0,
+            "beats_per_minute must be finite and strictly positive"
+        );
+        Duration::from_secs_f64(60.0 / self.beats_per_minute)
+    }
+
+    /// The duration of a single bar (one cycle).
+    #[must_use]
+    pub fn bar(&self) -> Duration {
+        self.beat() * self.cycles_per_bar
+    }
+
+    /// Computes the quantized firings whose *onset* falls in the window
+    /// `[start, stop)`.
+    ///
+    /// Each grid line at `n * step + phase` fires exactly once, in the window
+    /// that contains its onset — so stepping the clock forward in contiguous
+    /// ticks never re-fires the same slot. The [`QuantizedInterval`]'s `whole`
+    /// is the full slot `[onset, onset + step]` and its `part` is the portion of
+    /// that slot lying within this window (so `part` shrinks to the tick when a
+    /// slot spans many ticks). `whole` is modelled as optional to represent
+    /// fragments delivered by external, non-contiguous range queries; the
+    /// contiguous scheduler here always supplies it.
+    #[must_use]
+    pub fn quantize(
+        &self,
+        subdivision: Fraction,
+        phase: Option<Fraction>,
+        start: Duration,
+        stop: Duration,
+    ) -> Vec<QuantizedInterval> {
+        let bar = self.bar().as_secs_f64();
+        let step = bar * subdivision.as_ratio();
+        let phase_off = phase.map_or(0.0, |p| bar * p.as_ratio());
+        let (s, e) = (start.as_secs_f64(), stop.as_secs_f64());
+
+        let mut out = Vec::new();
+        if step <= 0.0 {
+            return out;
+        }
+
+        let mut n = (((s - phase_off) / step).floor() as i64).max(0);
+        loop {
+            let onset = n as f64 * step + phase_off;
+            if onset >= e {
+                break;
+            }
+            if onset >= s {
+                let whole_stop = onset + step;
+                out.push(QuantizedInterval {
+                    whole: Some((
+                        Duration::from_secs_f64(onset),
+                        Duration::from_secs_f64(whole_stop),
+                    )),
+                    part: (
+                        Duration::from_secs_f64(onset),
+                        Duration::from_secs_f64(whole_stop.min(e)),
+                    ),
+                });
+            }
+            n += 1;
+        }
+        out
+    }
+}
+
+impl Default for TempoContext {
+    fn default() -> Self {
+        Self {
+            beats_per_minute: 120.0,
+            cycles_per_bar: 4,
+        }
+    }
+}
+
+/// Owns a voice connection's audio pipeline and its global event listeners.
+pub struct Driver {
+    global: EventStore,
+    tempo: TempoContext,
+    clock: Duration,
+}
+
+impl Driver {
+    /// Creates a new, unconnected driver.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            global: EventStore::default(),
+            tempo: TempoContext::default(),
+            clock: Duration::ZERO,
+        }
+    }
+
+    /// Registers a global event handler against `event`.
+    pub fn add_global_event<F: EventHandler + 'static>(&mut self, event: Event, action: F) {
+        self.global.add_event(EventData::new(event, action));
+    }
+
+    /// Subscribes to a live stream of the driver's global events.
+    ///
+    /// Returns an [`EventSubscription`] yielding owned snapshots of each event
+    /// matching `filter`, as an async-pull alternative to registering an
+    /// [`EventHandler`]. The same dispatch path feeds both.
+    #[must_use]
+    pub fn events(&self, filter: EventFilter) -> EventSubscription {
+        self.global.subscribe(filter)
+    }
+
+    /// Sets the tempo context used to schedule [`Event::Quantized`] events.
+    ///
+    /// # Panics
+    /// Panics if `beats_per_minute` is not finite and strictly positive, or if
+    /// `cycles_per_bar` is zero — either would make the musical grid degenerate:
+    /// such a tempo would cause [`advance`] to panic when it derives the beat
+    /// duration, and a zero-beat bar would leave no quantized event able to fire.
+    ///
+    /// [`advance`]: Self::advance
+    pub fn set_tempo(&mut self, beats_per_minute: f64, cycles_per_bar: u32) {
+        assert!(
+            beats_per_minute.is_finite() && beats_per_minute > 0.0,
+            "beats_per_minute must be finite and strictly positive"
+        );
+        assert!(cycles_per_bar > 0, "cycles_per_bar must be non-zero");
+        self.tempo = TempoContext {
+            beats_per_minute,
+            cycles_per_bar,
+        };
+    }
+
+    /// Advances the driver clock by `delta`, dispatching any quantized events
+    /// whose grid boundaries fall within the elapsed window.
+    pub async fn advance(&mut self, delta: Duration) {
+        let stop = self.clock + delta;
+        self.global.fire_quantized(self.tempo, self.clock, stop).await;
+        self.clock = stop;
+    }
+}
+
+impl Default for Driver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EventContext;
+    use async_trait::async_trait;
+
+    fn dur(secs: f64) -> Duration {
+        Duration::from_secs_f64(secs)
+    }
+
+    struct NoopHandler;
+
+    #[async_trait]
+    impl EventHandler for NoopHandler {
+        async fn act(&self, _ctx: &EventContext<'_>) -> Option<Event> {
+            None
+        }
+    }
+
+    #[test]
+    fn aligned_firing_carries_whole() {
+        // 120bpm, 4 beats/bar => beat = 0.5s, bar = 2s; 1/4 subdivision = 0.5s.
+        let tempo = TempoContext::default();
+        let got = tempo.quantize(Fraction::new(1, 4), None, dur(0.0), dur(0.5));
+
+        assert_eq!(got.len(), 1);
+        assert_eq!(got[0].whole, Some((dur(0.0), dur(0.5))));
+        assert_eq!(got[0].part, (dur(0.0), dur(0.5)));
+    }
+
+    #[tokio::test]
+    async fn subscription_receives_quantized_firings() {
+        use crate::events::{EventContextSnapshot, EventFilter};
+
+        let mut driver = Driver::new();
+        driver.add_global_event(
+            Event::Quantized {
+                subdivision: Fraction::new(1, 4),
+                phase: None,
+            },
+            // A subscriber-only flow still needs at least one registered event
+            // for the driver to schedule against; this handler is a no-op.
+            NoopHandler,
+        );
+
+        let mut sub = driver.events(EventFilter::only([crate::events::EventClass::Quantized]));
+        driver.advance(Duration::from_secs_f64(0.5)).await;
+
+        match sub.recv().await.unwrap() {
+            EventContextSnapshot::Quantized(interval) => {
+                assert_eq!(interval.whole, Some((dur(0.0), dur(0.5))));
+            },
+            other => panic!("expected a quantized snapshot, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn slot_fires_only_in_the_window_containing_its_onset() {
+        let tempo = TempoContext::default();
+        // The 0.5s slot opened at onset 0.0; a later window inside it must not
+        // re-fire it, or contiguous ticks would fire the same slot repeatedly.
+        let got = tempo.quantize(Fraction::new(1, 4), None, dur(0.25), dur(0.5));
+
+        assert!(got.is_empty(), "a window past the onset must not re-fire the slot");
+    }
+
+    #[test]
+    fn part_shrinks_to_the_tick_while_whole_spans_the_slot() {
+        let tempo = TempoContext::default();
+        // A 20ms tick at the onset of a 0.5s beat: part is the tick, whole the beat.
+        let got = tempo.quantize(Fraction::new(1, 4), None, dur(0.0), dur(0.02));
+
+        assert_eq!(got.len(), 1);
+        assert_eq!(got[0].whole, Some((dur(0.0), dur(0.5))));
+        assert_eq!(got[0].part, (dur(0.0), dur(0.02)));
+    }
+
+    #[test]
+    #[should_panic(expected = "finite and strictly positive")]
+    fn non_positive_bpm_is_rejected() {
+        let mut driver = Driver::new();
+        driver.set_tempo(0.0, 4);
+    }
+}
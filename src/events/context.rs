@@ -0,0 +1,120 @@
+//! Contexts handed to an [`EventHandler`] when one of its events fires.
+//!
+//! [`EventHandler`]: super::EventHandler
+
+use super::CoreEvent;
+use crate::tracks::TrackHandle;
+use std::time::Duration;
+use uuid::Uuid;
+
+/// The interval pair delivered with an [`Event::Quantized`] firing.
+///
+/// Modelled on TidalCycles' part/whole events: `whole` is the full grid slot
+/// the firing belongs to (one step of its subdivision, e.g. one beat for `1/4`
+/// or one bar for `1/1`), and `part` is the sub-span which
+/// actually elapsed in this tick. Handlers use `part` for duration and `whole`
+/// to learn the intended musical slot.
+///
+/// `whole` is `None` for a *fragment* — a firing observed against a range which
+/// does not contain its onset — matching Tidal's optional-whole semantics.
+///
+/// [`Event::Quantized`]: super::Event::Quantized
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QuantizedInterval {
+    /// The full musical slot the firing belongs to, as a `(start, stop)` pair.
+    pub whole: Option<(Duration, Duration)>,
+    /// The sub-span which elapsed in this tick, as a `(start, stop)` pair.
+    pub part: (Duration, Duration),
+}
+
+/// Information about an event which has just fired.
+///
+/// Handlers receive this by shared reference. Track contexts borrow the set of
+/// tracks affected in this tick, so the handler can inspect them without the
+/// store cloning state on every dispatch.
+#[non_exhaustive]
+pub enum EventContext<'a> {
+    /// The set of tracks affected by a track/timed event in this tick.
+    ///
+    /// When fired locally this holds the single parent track. When fired
+    /// globally, a coalescing handler receives every track which triggered the
+    /// event in the 20ms window; any other handler is called once per trigger,
+    /// with only that trigger's track.
+    Track(&'a [&'a TrackHandle]),
+    /// A driver core event.
+    Core(CoreEvent),
+    /// A global timer tick with no further payload.
+    Tick,
+    /// A tempo-quantized firing, carrying its `whole`/`part` intervals.
+    Quantized(QuantizedInterval),
+}
+
+impl EventContext<'_> {
+    /// Produces an owned, transport-safe snapshot of this context.
+    ///
+    /// Borrowed track references are reduced to their [`Uuid`]s so the result
+    /// can be sent over a [subscription] channel or serialized, without holding
+    /// the dispatch-time borrows.
+    ///
+    /// [subscription]: super::EventSubscription
+    #[must_use]
+    pub fn snapshot(&self) -> EventContextSnapshot {
+        match self {
+            Self::Track(tracks) => {
+                EventContextSnapshot::Track(tracks.iter().map(|t| t.uuid()).collect())
+            },
+            Self::Core(evt) => EventContextSnapshot::Core(*evt),
+            Self::Tick => EventContextSnapshot::Tick,
+            Self::Quantized(interval) => EventContextSnapshot::Quantized(*interval),
+        }
+    }
+}
+
+/// An owned counterpart to [`EventContext`], carrying only transport-safe data.
+///
+/// This is what live [subscribers] receive and what serializes cleanly under
+/// the `serde` feature.
+///
+/// [subscribers]: super::EventSubscription
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum EventContextSnapshot {
+    /// The identifiers of the tracks affected in this tick.
+    Track(Vec<Uuid>),
+    /// A driver core event.
+    Core(CoreEvent),
+    /// A global timer tick.
+    Tick,
+    /// A tempo-quantized firing.
+    Quantized(QuantizedInterval),
+}
+
+impl EventContextSnapshot {
+    /// The [`EventClass`] this snapshot belongs to, used for subscription
+    /// filtering.
+    #[must_use]
+    pub fn class(&self) -> EventClass {
+        match self {
+            Self::Track(_) => EventClass::Track,
+            Self::Core(_) => EventClass::Core,
+            Self::Tick => EventClass::Tick,
+            Self::Quantized(_) => EventClass::Quantized,
+        }
+    }
+}
+
+/// The broad class of an event, used to select which firings a subscription
+/// receives.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum EventClass {
+    /// Track state changes.
+    Track,
+    /// Core driver events.
+    Core,
+    /// Global timer ticks.
+    Tick,
+    /// Tempo-quantized firings.
+    Quantized,
+}
@@ -0,0 +1,27 @@
+//! Events fired by the driver itself, rather than by an individual track.
+
+/// Events which are generated by the driver's connection and receive path.
+///
+/// Core events **must** be registered globally, as attaching them to a track
+/// is a no-op.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum CoreEvent {
+    /// Fired whenever a user's speaking state is updated.
+    SpeakingStateUpdate,
+    /// Fired whenever a source starts or stops speaking.
+    SpeakingUpdate,
+    /// Fired on receipt of a voice packet from another stream.
+    VoicePacket,
+    /// Fired on receipt of an RTCP packet.
+    RtcpPacket,
+    /// Fired whenever a client disconnects from the call.
+    ClientDisconnect,
+    /// Fired when the driver connects to a voice channel.
+    DriverConnect,
+    /// Fired when the driver reconnects to a voice channel.
+    DriverReconnect,
+    /// Fired when the driver disconnects from a voice channel.
+    DriverDisconnect,
+}
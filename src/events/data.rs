@@ -0,0 +1,69 @@
+//! Storage for a registered event and the handler it drives.
+
+use super::{Event, EventHandler};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// A registered `(Event, EventHandler)` pair, as stored inside an
+/// [`EventStore`].
+///
+/// [`EventStore`]: super::EventStore
+pub struct EventData {
+    pub(crate) event: Event,
+    pub(crate) action: Box<dyn EventHandler>,
+    pub(crate) coalesce: bool,
+    pub(crate) pending: AtomicBool,
+}
+
+impl EventData {
+    /// Creates a new event and its associated handler.
+    ///
+    /// When registered globally, the handler fires once per observed trigger in
+    /// a tick. Use [`coalesced`] to collapse repeated triggers within a tick
+    /// into a single call instead.
+    ///
+    /// [`coalesced`]: Self::coalesced
+    #[must_use]
+    pub fn new<F: EventHandler + 'static>(event: Event, action: F) -> Self {
+        Self {
+            event,
+            action: Box::new(action),
+            coalesce: false,
+            pending: AtomicBool::new(false),
+        }
+    }
+
+    /// Creates a new event and handler which coalesces repeated triggers.
+    ///
+    /// When many tracks trigger this event in the same 20ms tick, the handler
+    /// is invoked only once, with an [`EventContext`] carrying the full set of
+    /// affected tracks, rather than once per trigger.
+    ///
+    /// [`EventContext`]: super::EventContext
+    #[must_use]
+    pub fn coalesced<F: EventHandler + 'static>(event: Event, action: F) -> Self {
+        Self {
+            coalesce: true,
+            ..Self::new(event, action)
+        }
+    }
+
+    /// The event this handler is currently listening for.
+    #[must_use]
+    pub fn event(&self) -> Event {
+        self.event
+    }
+
+    /// Flags that a trigger for this event was observed in the current tick.
+    pub(crate) fn mark_pending(&self) {
+        self.pending.store(true, Ordering::Release);
+    }
+
+    /// Atomically clears and returns the pending flag.
+    ///
+    /// Resetting *before* `act` runs means a trigger which arrives mid-dispatch
+    /// simply re-arms the flag and is picked up on the next tick, rather than
+    /// being lost.
+    pub(crate) fn take_pending(&self) -> bool {
+        self.pending.swap(false, Ordering::AcqRel)
+    }
+}
@@ -55,14 +55,13 @@ mod track;
 mod untimed;
 
 pub use self::{
-    context::{context_data, EventContext},
+    context::{EventClass, EventContext, EventContextSnapshot, QuantizedInterval},
     core::*,
     data::*,
-    store::*,
+    store::{EventFilter, EventStore, EventSubscription},
     track::*,
     untimed::*,
 };
-pub(crate) use context::{internal_data, CoreContext};
 
 use async_trait::async_trait;
 use std::time::Duration;
@@ -74,6 +73,24 @@ use std::time::Duration;
 pub trait EventHandler: Send + Sync {
     /// Respond to one received event.
     async fn act(&self, ctx: &EventContext<'_>) -> Option<Event>;
+
+    /// Decide whether this handler cares about `ctx` *before* [`act`] is called.
+    ///
+    /// The event `store` queries this synchronously on each matching event: when
+    /// it returns `false`, [`act`] is skipped entirely so the handler pays nothing
+    /// for constructing or awaiting the async body. Unlike returning
+    /// [`Event::Cancel`] from [`act`], declining here leaves the listener
+    /// registered, so a later event may still be handled.
+    ///
+    /// This is most useful for handlers attached globally to busy drivers which
+    /// only react to a specific [`TrackEvent`] variant, or to a track whose UUID
+    /// matches. The default implementation accepts every event.
+    ///
+    /// [`act`]: Self::act
+    fn event_enabled(&self, ctx: &EventContext<'_>) -> bool {
+        let _ = ctx;
+        true
+    }
 }
 
 /// Classes of event which may occur, triggering a handler
@@ -88,6 +105,7 @@ pub trait EventHandler: Send + Sync {
 ///
 /// [`EventData::new`]: EventData::new
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum Event {
     /// Periodic events rely upon two parameters: a *period*
@@ -107,6 +125,33 @@ pub enum Event {
     ///
     /// [`EventData`]: EventData
     Delayed(Duration),
+    /// Quantized events fire aligned to a musical grid rather than
+    /// wall-clock or playback offsets.
+    ///
+    /// Firings are scheduled against the [`Driver`]'s tempo context
+    /// (BPM and cycles-per-bar): *subdivision* gives the grid spacing
+    /// as a [`Fraction`] of a cycle (e.g. `1/4` for each beat of a
+    /// four-beat bar), and the optional *phase* shifts the whole grid
+    /// forward from the cycle start, also as a fraction of one cycle.
+    ///
+    /// Each firing delivers an [`EventContext`] exposing the `whole`
+    /// (the full grid slot, one *subdivision* long, the firing belongs to) and the `part`
+    /// (the sub-span which actually elapsed in this tick). The `whole`
+    /// is `None` for a fragment — a firing observed against a range
+    /// that does not contain its onset.
+    ///
+    /// Quantized events repeat automatically so long as the `action`
+    /// in [`EventData`] returns `None`.
+    ///
+    /// [`Driver`]: crate::driver::Driver
+    /// [`EventData`]: EventData
+    Quantized {
+        /// Grid spacing as a fraction of one cycle.
+        subdivision: Fraction,
+        /// Offset of the grid from the cycle start, as a fraction of one
+        /// cycle (not of `subdivision`), if any.
+        phase: Option<Fraction>,
+    },
     /// Track events correspond to certain actions or changes
     /// of state, such as a track finishing, looping, or being
     /// manually stopped.
@@ -144,4 +189,88 @@ impl From<CoreEvent> for Event {
     fn from(evt: CoreEvent) -> Self {
         Event::Core(evt)
     }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests {
+    use super::*;
+
+    #[test]
+    fn event_round_trips_through_json() {
+        let event = Event::Quantized {
+            subdivision: Fraction::new(1, 4),
+            phase: Some(Fraction::new(1, 8)),
+        };
+        let json = serde_json::to_string(&event).unwrap();
+        let back: Event = serde_json::from_str(&json).unwrap();
+        assert_eq!(event, back);
+    }
+
+    #[test]
+    fn zero_denominator_is_rejected() {
+        let err =
+            serde_json::from_str::<Fraction>(r#"{"numerator":1,"denominator":0}"#).unwrap_err();
+        assert!(err.to_string().contains("non-zero"));
+    }
+}
+
+/// A rational position or span on the musical grid, measured in cycles.
+///
+/// Used by [`Event::Quantized`] to express subdivisions and phases
+/// independently of the current tempo; the driver resolves these into
+/// concrete [`Duration`]s using its BPM and cycles-per-bar.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "FractionRepr"))]
+pub struct Fraction {
+    /// Numerator of the fraction.
+    pub numerator: u32,
+    /// Denominator of the fraction; must be non-zero.
+    pub denominator: u32,
+}
+
+/// Transport representation of [`Fraction`], validated on the way in so a
+/// deserialized `denominator` of zero is rejected rather than yielding a
+/// non-finite ratio from [`Fraction::as_ratio`].
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct FractionRepr {
+    numerator: u32,
+    denominator: u32,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<FractionRepr> for Fraction {
+    type Error = &'static str;
+
+    fn try_from(repr: FractionRepr) -> Result<Self, Self::Error> {
+        if repr.denominator == 0 {
+            return Err("Fraction denominator must be non-zero");
+        }
+        Ok(Self {
+            numerator: repr.numerator,
+            denominator: repr.denominator,
+        })
+    }
+}
+
+impl Fraction {
+    /// Creates a new `Fraction` from a *numerator* and *denominator*.
+    ///
+    /// # Panics
+    /// Panics if `denominator` is zero.
+    #[must_use]
+    pub fn new(numerator: u32, denominator: u32) -> Self {
+        assert!(denominator != 0, "Fraction denominator must be non-zero");
+        Self {
+            numerator,
+            denominator,
+        }
+    }
+
+    /// Returns the fraction as a cycle-relative ratio.
+    #[must_use]
+    pub fn as_ratio(self) -> f64 {
+        f64::from(self.numerator) / f64::from(self.denominator)
+    }
 }
\ No newline at end of file
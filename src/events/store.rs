@@ -0,0 +1,506 @@
+//! The per-track and global stores which hold and dispatch [`EventData`].
+
+use super::{
+    Event, EventClass, EventContext, EventContextSnapshot, EventData, QuantizedInterval,
+    TrackEvent,
+};
+use crate::driver::TempoContext;
+use crate::tracks::TrackHandle;
+use std::time::Duration;
+use tokio::sync::broadcast;
+use uuid::Uuid;
+
+/// Default capacity of the per-store broadcast channel feeding subscribers.
+const SUBSCRIBER_CAPACITY: usize = 256;
+
+/// A collection of registered events and their handlers.
+///
+/// Each driver holds one global store, and each track holds a local store. The
+/// store owns the dispatch loop: for every handler it dispatches to it consults
+/// [`EventHandler::event_enabled`] first and only then awaits [`act`], removing
+/// any handler which returns [`Event::Cancel`]. [`flush_tick`] and
+/// [`fire_quantized`] dispatch only to handlers registered for the matching
+/// [`Event`]; [`fire`] offers its context to every registered handler.
+///
+/// [`flush_tick`]: Self::flush_tick
+/// [`fire_quantized`]: Self::fire_quantized
+/// [`fire`]: Self::fire
+/// [`EventHandler::event_enabled`]: super::EventHandler::event_enabled
+/// [`act`]: super::EventHandler::act
+pub struct EventStore {
+    events: Vec<EventData>,
+    pending_tracks: Vec<(TrackEvent, TrackHandle)>,
+    subscribers: broadcast::Sender<EventContextSnapshot>,
+}
+
+impl Default for EventStore {
+    fn default() -> Self {
+        Self::new(broadcast::channel(SUBSCRIBER_CAPACITY).0)
+    }
+}
+
+impl EventStore {
+    /// Creates a store which forwards fired events to `subscribers`.
+    #[must_use]
+    pub fn new(subscribers: broadcast::Sender<EventContextSnapshot>) -> Self {
+        Self {
+            events: Vec::new(),
+            pending_tracks: Vec::new(),
+            subscribers,
+        }
+    }
+
+    /// Registers a new event and handler with this store.
+    pub fn add_event(&mut self, data: EventData) {
+        self.events.push(data);
+    }
+
+    /// Subscribes to a live stream of fired events matching `filter`.
+    ///
+    /// This is the async-pull counterpart to registering an [`EventHandler`]:
+    /// the store forwards each fired context to every subscription in addition
+    /// to dispatching it to registered handlers.
+    ///
+    /// [`EventHandler`]: super::EventHandler
+    #[must_use]
+    pub fn subscribe(&self, filter: EventFilter) -> EventSubscription {
+        EventSubscription::new(self.subscribers.subscribe(), filter)
+    }
+
+    /// Whether any live subscription is currently listening.
+    ///
+    /// Callers check this before building a snapshot so the hot dispatch path
+    /// pays no allocation cost when nobody is subscribed.
+    fn has_subscribers(&self) -> bool {
+        self.subscribers.receiver_count() > 0
+    }
+
+    /// Forwards a fired context to any live subscribers.
+    fn broadcast(&self, snapshot: EventContextSnapshot) {
+        // A send error only means there are currently no subscribers.
+        let _ = self.subscribers.send(snapshot);
+    }
+
+    /// Records that `track` fired `event` during the current tick.
+    ///
+    /// Only coalescing handlers actually listening for `event` are armed, so
+    /// that [`flush_tick`] collapses this tick's matching triggers into a single
+    /// dispatch carrying all affected tracks. Fire-every-occurrence handlers are
+    /// dispatched once per recorded trigger instead.
+    ///
+    /// [`flush_tick`]: Self::flush_tick
+    pub fn note_tick(&mut self, event: TrackEvent, track: &TrackHandle) {
+        self.pending_tracks.push((event, track.clone()));
+        for data in &self.events {
+            if data.coalesce && data.event == Event::Track(event) {
+                data.mark_pending();
+            }
+        }
+    }
+
+    /// Dispatches the triggers accumulated this tick, then clears them.
+    ///
+    /// Each handler only sees triggers matching its own [`Event`]. A coalescing
+    /// handler fires at most once, with every track that triggered its event;
+    /// its pending flag is reset atomically *before* `act`, so a trigger
+    /// arriving mid-dispatch re-arms it for the next tick. A fire-every-
+    /// occurrence handler fires once per matching trigger, each call carrying
+    /// only that occurrence's single track. Both respect [`event_enabled`] and
+    /// [`Event::Cancel`].
+    ///
+    /// [`event_enabled`]: super::EventHandler::event_enabled
+    pub async fn flush_tick(&mut self) {
+        // Forward one snapshot per distinct track event to subscribers, carrying
+        // every track which fired it. This runs independently of the handler
+        // loop, so a subscriber-only flow still sees the tick even with no
+        // registered handlers.
+        if self.has_subscribers() {
+            let mut broadcast_done: Vec<TrackEvent> = Vec::new();
+            for (evt, _) in &self.pending_tracks {
+                if broadcast_done.contains(evt) {
+                    continue;
+                }
+                broadcast_done.push(*evt);
+                let tracks: Vec<Uuid> = self
+                    .pending_tracks
+                    .iter()
+                    .filter(|(e, _)| e == evt)
+                    .map(|(_, handle)| handle.uuid())
+                    .collect();
+                self.broadcast(EventContextSnapshot::Track(tracks));
+            }
+        }
+
+        let mut to_remove = Vec::new();
+        let mut to_update = Vec::new();
+
+        for (i, data) in self.events.iter().enumerate() {
+            let Event::Track(listening_for) = data.event else {
+                continue;
+            };
+
+            let matching: Vec<&TrackHandle> = self
+                .pending_tracks
+                .iter()
+                .filter_map(|(evt, handle)| (*evt == listening_for).then_some(handle))
+                .collect();
+
+            // Reset the coalesce flag before dispatch so a mid-dispatch trigger
+            // re-arms rather than being lost.
+            let armed = data.coalesce && data.take_pending();
+            if matching.is_empty() {
+                continue;
+            }
+
+            // One batch of all tracks when coalescing; one single-track batch
+            // per occurrence otherwise.
+            let batches: Vec<Vec<&TrackHandle>> = if data.coalesce {
+                if !armed {
+                    continue;
+                }
+                vec![matching]
+            } else {
+                matching.into_iter().map(|handle| vec![handle]).collect()
+            };
+
+            for batch in batches {
+                let ctx = EventContext::Track(&batch);
+                if !data.action.event_enabled(&ctx) {
+                    continue;
+                }
+
+                match data.action.act(&ctx).await {
+                    Some(Event::Cancel) => {
+                        to_remove.push(i);
+                        break;
+                    },
+                    Some(evt) => to_update.push((i, evt)),
+                    None => {},
+                }
+            }
+        }
+
+        for (i, evt) in to_update {
+            self.events[i].event = evt;
+        }
+        for i in to_remove.into_iter().rev() {
+            self.events.remove(i);
+        }
+        self.pending_tracks.clear();
+    }
+
+    /// Dispatches `ctx` to every registered handler.
+    ///
+    /// A handler is skipped entirely — without `act` being awaited — whenever
+    /// its [`event_enabled`] pre-filter returns `false`. Declining there leaves
+    /// the handler registered, unlike returning [`Event::Cancel`] from `act`,
+    /// which removes it.
+    ///
+    /// [`event_enabled`]: super::EventHandler::event_enabled
+    pub async fn fire(&mut self, ctx: EventContext<'_>) {
+        if self.has_subscribers() {
+            self.broadcast(ctx.snapshot());
+        }
+
+        let mut to_remove = Vec::new();
+        let mut to_update = Vec::new();
+
+        for (i, data) in self.events.iter().enumerate() {
+            if !data.action.event_enabled(&ctx) {
+                continue;
+            }
+
+            match data.action.act(&ctx).await {
+                Some(Event::Cancel) => to_remove.push(i),
+                Some(evt) => to_update.push((i, evt)),
+                None => {},
+            }
+        }
+
+        for (i, evt) in to_update {
+            self.events[i].event = evt;
+        }
+        for i in to_remove.into_iter().rev() {
+            self.events.remove(i);
+        }
+    }
+
+    /// Dispatches every registered [`Event::Quantized`] handler whose grid
+    /// boundaries fall within the window `[start, stop)`.
+    ///
+    /// Each firing carries an [`EventContext::Quantized`] with the `whole`/`part`
+    /// intervals derived from `tempo`. As with [`fire`], handlers may decline via
+    /// [`event_enabled`] or remove themselves by returning [`Event::Cancel`].
+    ///
+    /// [`fire`]: Self::fire
+    /// [`event_enabled`]: super::EventHandler::event_enabled
+    pub async fn fire_quantized(&mut self, tempo: TempoContext, start: Duration, stop: Duration) {
+        let mut to_remove = Vec::new();
+        let mut to_update = Vec::new();
+        // Intervals already forwarded to subscribers this call, so that several
+        // handlers sharing a subdivision don't each re-broadcast the same slot.
+        let mut broadcast_done: Vec<QuantizedInterval> = Vec::new();
+
+        for (i, data) in self.events.iter().enumerate() {
+            let Event::Quantized { subdivision, phase } = data.event else {
+                continue;
+            };
+
+            for interval in tempo.quantize(subdivision, phase, start, stop) {
+                let ctx = EventContext::Quantized(interval);
+                if self.has_subscribers() && !broadcast_done.contains(&interval) {
+                    broadcast_done.push(interval);
+                    self.broadcast(ctx.snapshot());
+                }
+                if !data.action.event_enabled(&ctx) {
+                    continue;
+                }
+
+                match data.action.act(&ctx).await {
+                    Some(Event::Cancel) => {
+                        to_remove.push(i);
+                        break;
+                    },
+                    Some(evt) => to_update.push((i, evt)),
+                    None => {},
+                }
+            }
+        }
+
+        for (i, evt) in to_update {
+            self.events[i].event = evt;
+        }
+        for i in to_remove.into_iter().rev() {
+            self.events.remove(i);
+        }
+    }
+
+    /// The number of handlers currently registered.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Whether any handlers are currently registered.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+}
+
+/// Selects which [`EventClass`]es a subscription receives.
+#[derive(Clone, Debug)]
+pub struct EventFilter {
+    classes: Vec<EventClass>,
+}
+
+impl EventFilter {
+    /// Accepts every class of event.
+    #[must_use]
+    pub fn all() -> Self {
+        Self {
+            classes: vec![
+                EventClass::Track,
+                EventClass::Core,
+                EventClass::Tick,
+                EventClass::Quantized,
+            ],
+        }
+    }
+
+    /// Accepts only the given classes of event.
+    #[must_use]
+    pub fn only(classes: impl IntoIterator<Item = EventClass>) -> Self {
+        Self {
+            classes: classes.into_iter().collect(),
+        }
+    }
+
+    fn accepts(&self, snapshot: &EventContextSnapshot) -> bool {
+        self.classes.contains(&snapshot.class())
+    }
+}
+
+/// A live, async-pull stream of fired events, created by [`EventStore::subscribe`].
+///
+/// This composes with `tokio::select!` and lets callers `await` events without
+/// implementing [`EventHandler`].
+///
+/// [`EventHandler`]: super::EventHandler
+pub struct EventSubscription {
+    rx: broadcast::Receiver<EventContextSnapshot>,
+    filter: EventFilter,
+}
+
+impl EventSubscription {
+    /// Wraps a broadcast receiver with a class filter.
+    pub(crate) fn new(
+        rx: broadcast::Receiver<EventContextSnapshot>,
+        filter: EventFilter,
+    ) -> Self {
+        Self { rx, filter }
+    }
+
+    /// Awaits the next event matching this subscription's filter.
+    ///
+    /// Non-matching events are skipped transparently. Errors are surfaced as
+    /// [`broadcast::error::RecvError`], e.g. [`Lagged`] when a slow consumer
+    /// misses events.
+    ///
+    /// [`Lagged`]: broadcast::error::RecvError::Lagged
+    pub async fn recv(
+        &mut self,
+    ) -> Result<EventContextSnapshot, broadcast::error::RecvError> {
+        loop {
+            let snapshot = self.rx.recv().await?;
+            if self.filter.accepts(&snapshot) {
+                return Ok(snapshot);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::{Event, EventHandler, TrackEvent};
+    use crate::tracks::TrackHandle;
+    use async_trait::async_trait;
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    };
+    use uuid::Uuid;
+
+    struct Counter {
+        enabled: bool,
+        calls: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl EventHandler for Counter {
+        async fn act(&self, _ctx: &EventContext<'_>) -> Option<Event> {
+            self.calls.fetch_add(1, Ordering::AcqRel);
+            None
+        }
+
+        fn event_enabled(&self, _ctx: &EventContext<'_>) -> bool {
+            self.enabled
+        }
+    }
+
+    /// Records the set of track UUIDs seen on each `act` call.
+    #[derive(Default)]
+    struct Recorder {
+        seen: Arc<Mutex<Vec<Vec<Uuid>>>>,
+    }
+
+    #[async_trait]
+    impl EventHandler for Recorder {
+        async fn act(&self, ctx: &EventContext<'_>) -> Option<Event> {
+            if let EventContext::Track(tracks) = ctx {
+                self.seen
+                    .lock()
+                    .unwrap()
+                    .push(tracks.iter().map(|t| t.uuid()).collect());
+            }
+            None
+        }
+    }
+
+    #[tokio::test]
+    async fn event_enabled_false_skips_act_but_keeps_listener() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let mut store = EventStore::default();
+        store.add_event(EventData::new(
+            Event::Track(TrackEvent::End),
+            Counter {
+                enabled: false,
+                calls: calls.clone(),
+            },
+        ));
+
+        store.fire(EventContext::Tick).await;
+
+        assert_eq!(calls.load(Ordering::Acquire), 0, "act must be skipped");
+        assert_eq!(store.len(), 1, "listener must remain registered");
+    }
+
+    #[tokio::test]
+    async fn event_enabled_true_runs_act() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let mut store = EventStore::default();
+        store.add_event(EventData::new(
+            Event::Track(TrackEvent::End),
+            Counter {
+                enabled: true,
+                calls: calls.clone(),
+            },
+        ));
+
+        store.fire(EventContext::Tick).await;
+
+        assert_eq!(calls.load(Ordering::Acquire), 1);
+    }
+
+    #[tokio::test]
+    async fn coalesced_handler_collapses_tick_triggers() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let mut store = EventStore::default();
+        store.add_event(EventData::coalesced(
+            Event::Track(TrackEvent::End),
+            Recorder { seen: seen.clone() },
+        ));
+
+        let a = TrackHandle::new(Uuid::from_u128(1));
+        let b = TrackHandle::new(Uuid::from_u128(2));
+        store.note_tick(TrackEvent::End, &a);
+        store.note_tick(TrackEvent::End, &b);
+        store.flush_tick().await;
+
+        let seen = seen.lock().unwrap();
+        assert_eq!(seen.len(), 1, "two triggers collapse to one call");
+        assert_eq!(
+            seen[0],
+            vec![Uuid::from_u128(1), Uuid::from_u128(2)],
+            "the single call carries every affected track"
+        );
+    }
+
+    #[tokio::test]
+    async fn fire_every_occurrence_handler_sees_its_own_track() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let mut store = EventStore::default();
+        store.add_event(EventData::new(
+            Event::Track(TrackEvent::End),
+            Recorder { seen: seen.clone() },
+        ));
+
+        store.note_tick(TrackEvent::End, &TrackHandle::new(Uuid::from_u128(1)));
+        store.note_tick(TrackEvent::End, &TrackHandle::new(Uuid::from_u128(2)));
+        store.flush_tick().await;
+
+        let seen = seen.lock().unwrap();
+        assert_eq!(
+            *seen,
+            vec![vec![Uuid::from_u128(1)], vec![Uuid::from_u128(2)]],
+            "each occurrence carries exactly its own track"
+        );
+    }
+
+    #[tokio::test]
+    async fn handler_ignores_triggers_for_other_events() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let mut store = EventStore::default();
+        store.add_event(EventData::coalesced(
+            Event::Track(TrackEvent::Play),
+            Recorder { seen: seen.clone() },
+        ));
+
+        // Only an End trigger occurs; a handler listening for Play must not fire.
+        store.note_tick(TrackEvent::End, &TrackHandle::new(Uuid::from_u128(1)));
+        store.flush_tick().await;
+
+        assert!(seen.lock().unwrap().is_empty(), "trigger must match the listened-for event");
+    }
+}
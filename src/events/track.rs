@@ -0,0 +1,20 @@
+//! Events corresponding to state changes on an individual track.
+
+/// Track events correspond to certain actions or changes of state, such as a
+/// track finishing, looping, or being manually stopped.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum TrackEvent {
+    /// The track began to play, having previously been stopped or paused.
+    Play,
+    /// The track was paused.
+    Pause,
+    /// The track ended, either by reaching the end of its source or being
+    /// stopped.
+    End,
+    /// The track looped back to its start.
+    Loop,
+    /// The track's source produced an unrecoverable error.
+    Error,
+}
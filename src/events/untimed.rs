@@ -0,0 +1,30 @@
+//! Non-timer events, shared between the track and core event families.
+
+use super::{CoreEvent, TrackEvent};
+
+/// Events which are not generated by a timer, i.e. track state changes and
+/// core driver events.
+///
+/// These are the event classes which can be coalesced or dispatched the moment
+/// their trigger is observed, rather than on a timed boundary.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum UntimedEvent {
+    /// A track state change.
+    Track(TrackEvent),
+    /// A core driver event.
+    Core(CoreEvent),
+}
+
+impl From<TrackEvent> for UntimedEvent {
+    fn from(evt: TrackEvent) -> Self {
+        UntimedEvent::Track(evt)
+    }
+}
+
+impl From<CoreEvent> for UntimedEvent {
+    fn from(evt: CoreEvent) -> Self {
+        UntimedEvent::Core(evt)
+    }
+}
@@ -0,0 +1,15 @@
+//! A Discord voice library, exposing the event machinery that drives tracks
+//! and the audio pipeline.
+//!
+//! The [`events`] module is the heart of how callers observe what the driver
+//! is doing: register an [`EventHandler`] against an [`Event`], or subscribe to
+//! a live stream of [`EventContext`] snapshots.
+//!
+//! [`events`]: crate::events
+//! [`EventHandler`]: crate::events::EventHandler
+//! [`Event`]: crate::events::Event
+//! [`EventContext`]: crate::events::EventContext
+
+pub mod driver;
+pub mod events;
+pub mod tracks;
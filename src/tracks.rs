@@ -0,0 +1,105 @@
+//! Handles and state for individual audio tracks.
+
+use crate::events::{
+    Event, EventContextSnapshot, EventData, EventFilter, EventHandler, EventStore,
+    EventSubscription,
+};
+use tokio::sync::broadcast;
+use uuid::Uuid;
+
+/// An owned audio track, along with the local event handlers attached to it.
+pub struct Track {
+    events: EventStore,
+    subscribers: broadcast::Sender<EventContextSnapshot>,
+}
+
+impl Track {
+    /// Creates a new track with no attached events.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a local event handler against `event`.
+    ///
+    /// Attaching a [`CoreEvent`] here is a no-op, as core events may only be
+    /// registered globally on the [`Driver`].
+    ///
+    /// [`CoreEvent`]: crate::events::CoreEvent
+    /// [`Driver`]: crate::driver::Driver
+    pub fn add_event<F: EventHandler + 'static>(&mut self, event: Event, action: F) {
+        if event.is_global_only() {
+            return;
+        }
+        self.events.add_event(EventData::new(event, action));
+    }
+
+    /// The store of local event handlers attached to this track.
+    pub fn events(&mut self) -> &mut EventStore {
+        &mut self.events
+    }
+
+    /// Produces a handle referring to this track under the given identifier.
+    #[must_use]
+    pub fn handle(&self, uuid: Uuid) -> TrackHandle {
+        TrackHandle {
+            uuid,
+            subscribers: self.subscribers.clone(),
+        }
+    }
+}
+
+impl Default for Track {
+    fn default() -> Self {
+        let (subscribers, _) = broadcast::channel(256);
+        Self {
+            events: EventStore::new(subscribers.clone()),
+            subscribers,
+        }
+    }
+}
+
+/// A reference to a single audio track owned by the driver.
+///
+/// Handles are cheap to clone and are the caller-facing means of inspecting or
+/// controlling a track.
+#[derive(Clone, Debug)]
+pub struct TrackHandle {
+    uuid: Uuid,
+    subscribers: broadcast::Sender<EventContextSnapshot>,
+}
+
+impl TrackHandle {
+    /// Creates a standalone handle for a track with the given identifier.
+    ///
+    /// Test-only: the handle owns a detached broadcast channel with no producer,
+    /// so [`events`] on it would never yield. Handles reaching callers are minted
+    /// by [`Track::handle`], which wires them to the track's own subscriber
+    /// channel.
+    ///
+    /// [`events`]: Self::events
+    #[cfg(test)]
+    #[must_use]
+    pub(crate) fn new(uuid: Uuid) -> Self {
+        Self {
+            uuid,
+            subscribers: broadcast::channel(256).0,
+        }
+    }
+
+    /// The unique identifier of this track.
+    #[must_use]
+    pub fn uuid(&self) -> Uuid {
+        self.uuid
+    }
+
+    /// Subscribes to a live stream of this track's local events.
+    ///
+    /// Returns an [`EventSubscription`] yielding owned snapshots of each event
+    /// matching `filter`, as an async-pull alternative to registering an
+    /// [`EventHandler`] on the track.
+    #[must_use]
+    pub fn events(&self, filter: EventFilter) -> EventSubscription {
+        EventSubscription::new(self.subscribers.subscribe(), filter)
+    }
+}